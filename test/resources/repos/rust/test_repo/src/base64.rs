@@ -0,0 +1,129 @@
+use crate::base::{Readable, Writable};
+
+const STANDARD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const PADDING: u8 = b'=';
+
+/// The base64 alphabet to encode or decode with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterSet {
+    /// The standard alphabet, using `+` and `/` with `=` padding.
+    Standard,
+    /// The URL- and filename-safe alphabet, using `-` and `_` with `=` padding.
+    UrlSafe,
+}
+
+impl CharacterSet {
+    fn alphabet(&self) -> &'static [u8; 64] {
+        match self {
+            CharacterSet::Standard => STANDARD_ALPHABET,
+            CharacterSet::UrlSafe => URL_SAFE_ALPHABET,
+        }
+    }
+
+    fn decode_byte(&self, ch: u8) -> Result<u8, String> {
+        self.alphabet()
+            .iter()
+            .position(|&c| c == ch)
+            .map(|pos| pos as u8)
+            .ok_or_else(|| format!("character '{}' is not in the base64 alphabet", ch as char))
+    }
+}
+
+/// Encodes a byte slice into a base64 `String` using the given alphabet.
+fn encode(data: &[u8], charset: CharacterSet) -> String {
+    let alphabet = charset.alphabet();
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(alphabet[(b0 >> 2) as usize] as char);
+        out.push(alphabet[((b0 & 0x03) << 4 | (b1.unwrap_or(0) >> 4)) as usize] as char);
+
+        match b1 {
+            Some(b1) => {
+                out.push(alphabet[((b1 & 0x0f) << 2 | (b2.unwrap_or(0) >> 6)) as usize] as char);
+            }
+            None => {
+                out.push(PADDING as char);
+                out.push(PADDING as char);
+                continue;
+            }
+        }
+
+        match b2 {
+            Some(b2) => out.push(alphabet[(b2 & 0x3f) as usize] as char),
+            None => out.push(PADDING as char),
+        }
+    }
+
+    out
+}
+
+/// Decodes a base64 string into its raw bytes using the given alphabet.
+fn decode(data: &str, charset: CharacterSet) -> Result<Vec<u8>, String> {
+    let bytes = data.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err("base64 input length must be a multiple of 4".to_string());
+    }
+
+    let mut out = Vec::with_capacity(bytes.len() / 4 * 3);
+    let mut seen_padding = false;
+
+    for chunk in bytes.chunks(4) {
+        if seen_padding {
+            return Err("padding may only appear in the final chunk".to_string());
+        }
+
+        let padding = chunk.iter().rev().take_while(|&&b| b == PADDING).count();
+        if chunk[..4 - padding].contains(&PADDING) {
+            return Err("padding may only appear at the end of a chunk".to_string());
+        }
+        seen_padding = padding > 0;
+        let mut sextets = [0u8; 4];
+        for (i, &b) in chunk.iter().enumerate() {
+            if b != PADDING {
+                sextets[i] = charset.decode_byte(b)?;
+            }
+        }
+
+        out.push(sextets[0] << 2 | sextets[1] >> 4);
+        if padding < 2 {
+            out.push(sextets[1] << 4 | sextets[2] >> 2);
+        }
+        if padding < 1 {
+            out.push(sextets[2] << 6 | sextets[3]);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Reads data out of a type as a base64-encoded string.
+pub trait ToBase64 {
+    fn read_base64(&self, charset: CharacterSet) -> Result<String, String>;
+}
+
+/// Writes a base64-encoded string into a type, decoding it first.
+pub trait FromBase64 {
+    fn write_base64(&mut self, data: &str, charset: CharacterSet) -> Result<(), String>;
+}
+
+impl<T: Readable> ToBase64 for T {
+    fn read_base64(&self, charset: CharacterSet) -> Result<String, String> {
+        let bytes = self.read()?;
+        Ok(encode(&bytes, charset))
+    }
+}
+
+impl<T: Writable> FromBase64 for T {
+    fn write_base64(&mut self, data: &str, charset: CharacterSet) -> Result<(), String> {
+        let bytes = decode(data, charset)?;
+        self.write(&bytes)
+    }
+}
@@ -4,6 +4,8 @@
 //! and documentation patterns.
 
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread;
 
 /// Adds two numbers together and returns the result.
 /// 
@@ -69,88 +71,238 @@ pub fn factorial(n: u32) -> Option<u64> {
 }
 
 /// A simple structure representing a point in 2D space.
-/// 
+///
 /// This struct demonstrates basic Rust struct syntax and documentation.
+///
+/// `Point` is generic over its coordinate type `T`, defaulting to `f64` so
+/// existing code that writes `Point` without a type argument keeps working
+/// unchanged. Use `Point<i32>` or `Point<f32>` directly when a narrower
+/// representation is a better fit.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Point {
+pub struct Point<T = f64> {
     /// The x-coordinate of the point
-    pub x: f64,
-    /// The y-coordinate of the point  
-    pub y: f64,
+    pub x: T,
+    /// The y-coordinate of the point
+    pub y: T,
 }
 
-impl Point {
+impl<T> Point<T> {
     /// Creates a new Point with the given coordinates.
-    /// 
+    ///
     /// # Arguments
-    /// 
+    ///
     /// * `x` - The x-coordinate
     /// * `y` - The y-coordinate
-    /// 
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use test_repo::Point;
-    /// 
+    ///
     /// let p = Point::new(1.0, 2.0);
     /// assert_eq!(p.x, 1.0);
     /// assert_eq!(p.y, 2.0);
     /// ```
-    pub fn new(x: f64, y: f64) -> Self {
+    pub fn new(x: T, y: T) -> Self {
         Point { x, y }
     }
-    
+}
+
+impl<T> Point<T>
+where
+    T: Copy
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Add<Output = T>
+        + Into<f64>,
+{
     /// Calculates the distance from this point to another point.
-    /// 
+    ///
     /// # Arguments
-    /// 
+    ///
     /// * `other` - The other point to calculate distance to
-    /// 
+    ///
     /// # Returns
-    /// 
-    /// Returns the Euclidean distance between the two points.
-    /// 
+    ///
+    /// Returns the Euclidean distance between the two points as an `f64`,
+    /// regardless of the coordinate type `T`.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use test_repo::Point;
-    /// 
+    ///
     /// let p1 = Point::new(0.0, 0.0);
     /// let p2 = Point::new(3.0, 4.0);
     /// assert_eq!(p1.distance_to(&p2), 5.0);
     /// ```
-    pub fn distance_to(&self, other: &Point) -> f64 {
+    pub fn distance_to(&self, other: &Point<T>) -> f64 {
         let dx = self.x - other.x;
         let dy = self.y - other.y;
-        (dx * dx + dy * dy).sqrt()
+        (dx * dx + dy * dy).into().sqrt()
     }
 }
 
-/// Processes a vector of numbers using a given function.
-/// 
+impl<T: std::ops::Add<Output = T>> std::ops::Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: std::ops::Sub<Output = T>> std::ops::Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: std::ops::Neg<Output = T>> std::ops::Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + std::ops::Mul<Output = T>> std::ops::Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, scalar: T) -> Point<T> {
+        Point::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T: Copy + std::ops::Div<Output = T>> std::ops::Div<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn div(self, scalar: T) -> Point<T> {
+        Point::new(self.x / scalar, self.y / scalar)
+    }
+}
+
+impl<T: std::ops::AddAssign> std::ops::AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: std::ops::SubAssign> std::ops::SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+/// Processes a vector of items through a fallible function, short-circuiting
+/// on the first error.
+///
 /// # Arguments
-/// 
-/// * `numbers` - A vector of i32 numbers to process
-/// * `processor` - A function that takes an i32 and returns an i32
-/// 
+///
+/// * `items` - The items to process, consumed in order
+/// * `f` - A function that converts an item into a result, or fails
+///
 /// # Returns
-/// 
-/// Returns a new vector with all numbers processed by the given function.
-/// 
+///
+/// Returns the processed items in order, or the first error `f` produced,
+/// annotated with the index of the item that failed.
+///
 /// # Examples
-/// 
+///
 /// ```
-/// use test_repo::process_numbers;
-/// 
+/// use test_repo::process_items;
+///
 /// let numbers = vec![1, 2, 3, 4];
-/// let doubled = process_numbers(numbers, |x| x * 2);
+/// let doubled = process_items(numbers, |x| Ok(x * 2)).unwrap();
 /// assert_eq!(doubled, vec![2, 4, 6, 8]);
+///
+/// let result = process_items(vec![1, 2, 3], |x| {
+///     if x == 2 { Err("bad value".to_string()) } else { Ok(x) }
+/// });
+/// assert!(result.is_err());
+/// ```
+pub fn process_items<T, U, F>(items: Vec<T>, f: F) -> Result<Vec<U>, String>
+where
+    F: Fn(T) -> Result<U, String>,
+{
+    let mut results = Vec::with_capacity(items.len());
+    for (index, item) in items.into_iter().enumerate() {
+        match f(item) {
+            Ok(value) => results.push(value),
+            Err(err) => return Err(format!("item {} failed: {}", index, err)),
+        }
+    }
+    Ok(results)
+}
+
+/// Parallel variant of [`process_items`] that splits `items` into chunks and
+/// processes each chunk on its own OS thread, sharing `f` behind an `Arc`.
+///
+/// Results are reassembled in the original item order regardless of which
+/// thread finishes first. Error messages still carry the original index of
+/// the failing item.
+///
+/// # Examples
+///
 /// ```
-pub fn process_numbers<F>(numbers: Vec<i32>, processor: F) -> Vec<i32>
+/// use test_repo::process_items_parallel;
+///
+/// let numbers = vec![1, 2, 3, 4, 5, 6, 7, 8];
+/// let doubled = process_items_parallel(numbers, |x| Ok(x * 2)).unwrap();
+/// assert_eq!(doubled, vec![2, 4, 6, 8, 10, 12, 14, 16]);
+/// ```
+pub fn process_items_parallel<T, U, F>(items: Vec<T>, f: F) -> Result<Vec<U>, String>
 where
-    F: Fn(i32) -> i32,
+    T: Send + 'static,
+    U: Send + 'static,
+    F: Fn(T) -> Result<U, String> + Send + Sync + 'static,
 {
-    numbers.into_iter().map(processor).collect()
+    if items.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let worker_count = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(items.len());
+    let chunk_size = items.len().div_ceil(worker_count);
+
+    let f = Arc::new(f);
+    let mut item_iter = items.into_iter();
+    let mut handles = Vec::with_capacity(worker_count);
+    let mut base_index = 0usize;
+
+    loop {
+        let chunk: Vec<T> = (&mut item_iter).take(chunk_size).collect();
+        if chunk.is_empty() {
+            break;
+        }
+
+        let f = Arc::clone(&f);
+        let start = base_index;
+        base_index += chunk.len();
+
+        handles.push(thread::spawn(move || {
+            let mut results = Vec::with_capacity(chunk.len());
+            for (offset, item) in chunk.into_iter().enumerate() {
+                match f(item) {
+                    Ok(value) => results.push(value),
+                    Err(err) => return Err(format!("item {} failed: {}", start + offset, err)),
+                }
+            }
+            Ok(results)
+        }));
+    }
+
+    let mut output = Vec::new();
+    for handle in handles {
+        let chunk_result = handle.join().expect("worker thread panicked")?;
+        output.extend(chunk_result);
+    }
+    Ok(output)
 }
 
 /// Creates a frequency map of characters in a string.
@@ -0,0 +1,70 @@
+use crate::base::Worker;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+type Job = Arc<dyn Worker + Send + Sync>;
+
+/// A fixed-size pool of OS threads that executes shared `Worker` handles
+/// concurrently, dispatching each submitted worker to whichever thread is
+/// free next.
+pub struct WorkerPool {
+    sender: mpsc::Sender<Job>,
+    threads: Vec<thread::JoinHandle<()>>,
+    results: Arc<Mutex<Vec<Result<(), String>>>>,
+}
+
+impl WorkerPool {
+    /// Spawns a pool backed by `size` OS threads sharing a single work queue.
+    pub fn new(size: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let mut threads = Vec::with_capacity(size);
+
+        for _ in 0..size {
+            let receiver = Arc::clone(&receiver);
+            let results = Arc::clone(&results);
+
+            threads.push(thread::spawn(move || loop {
+                let job = receiver.lock().unwrap().recv();
+                match job {
+                    Ok(worker) => {
+                        worker.execute();
+                        let result = worker.process();
+                        results.lock().unwrap().push(result);
+                    }
+                    Err(_) => break,
+                }
+            }));
+        }
+
+        Self {
+            sender,
+            threads,
+            results,
+        }
+    }
+
+    /// Enqueues a shared worker for execution by the pool. Because the
+    /// worker is held behind `Arc`, the same worker can be submitted by
+    /// multiple producers without cloning its underlying data.
+    pub fn submit(&self, worker: Arc<dyn Worker + Send + Sync>) {
+        self.sender
+            .send(worker)
+            .expect("worker pool queue has been closed");
+    }
+
+    /// Drains all pending work, waits for every thread to finish, and
+    /// returns the aggregated `process()` results in completion order.
+    pub fn join(self) -> Vec<Result<(), String>> {
+        drop(self.sender);
+        for thread in self.threads {
+            thread.join().expect("worker thread panicked");
+        }
+        Arc::try_unwrap(self.results)
+            .expect("all worker threads have joined")
+            .into_inner()
+            .unwrap()
+    }
+}